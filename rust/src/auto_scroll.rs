@@ -0,0 +1,196 @@
+use bevy::ecs::system::Query;
+use bevy::prelude::{App, Commands, Component, Entity, Plugin, Res, Time, Update, Without};
+use godot::builtin::{Rect2, Vector2};
+use godot::classes::canvas_item::TextureRepeat;
+use godot::classes::{AtlasTexture, Sprite2D};
+use godot::global::godot_warn;
+use godot_bevy::prelude::{GodotNodeHandle, Sprite2DMarker, main_thread_system};
+
+// Name of the metadata entry designers set on a Sprite2D to make it auto-scroll.
+//
+// In the Godot editor, select the Sprite2D, click "Add Metadata" at the bottom of the
+// inspector, name it `auto_scroll_velocity` and give it a `Vector2` value in pixels per second.
+// The layer's content moves in that direction, e.g. `(100, 0)` drifts clouds to the right.
+//
+// The layer loops its whole texture, so give it its own tileable image rather than a tile from
+// an atlas. Sprites using an `AtlasTexture` or a region smaller than the texture are skipped.
+const AUTO_SCROLL_META: &str = "auto_scroll_velocity";
+
+// Continuously scrolls background layers (clouds, stars, ...) at a fixed speed,
+// independently of any camera movement.
+pub struct AutoScrollPlugin;
+
+impl Plugin for AutoScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (auto_scroll_setup, auto_scroll_system));
+    }
+}
+
+// This component stores how fast a layer scrolls and how far it has scrolled so far.
+#[derive(Debug, Component)]
+pub struct AutoScroll {
+    pub velocity: Vector2,
+    offset: Vector2,
+    // The region position the designer set, which scrolling is relative to.
+    region_origin: Vector2,
+    // The texture size, used to wrap the offset so the layer repeats seamlessly.
+    wrap_size: Vector2,
+}
+
+// Marker for sprites that have already been checked for auto-scroll metadata.
+#[derive(Debug, Component)]
+struct AutoScrollChecked;
+
+// This system looks for Sprite2Ds tagged with the auto-scroll metadata and prepares them for scrolling.
+#[main_thread_system]
+fn auto_scroll_setup(
+    mut commands: Commands,
+    mut unchecked: Query<
+        (Entity, &mut GodotNodeHandle, &Sprite2DMarker),
+        Without<AutoScrollChecked>,
+    >,
+) {
+    for (entity, mut node_handle, _) in unchecked.iter_mut() {
+        let mut sprite = node_handle.get::<Sprite2D>();
+        if !sprite.has_meta(AUTO_SCROLL_META) {
+            // Not an auto-scroll layer, so there is no need to look at it again.
+            commands.entity(entity).insert(AutoScrollChecked);
+            continue;
+        }
+        let Ok(velocity) = sprite.get_meta(AUTO_SCROLL_META).try_to::<Vector2>() else {
+            godot_warn!(
+                "{}: `{}` must be a Vector2, skipping auto-scroll",
+                sprite.get_name(),
+                AUTO_SCROLL_META
+            );
+            commands.entity(entity).insert(AutoScrollChecked);
+            continue;
+        };
+        // The texture may be assigned after the node enters the tree, so try again next frame.
+        let Some(texture) = sprite.get_texture() else {
+            continue;
+        };
+        let wrap_size = texture.get_size();
+
+        // Wrapping happens at the texture size, so a layer showing only part of its texture
+        // would slide across the neighbouring pixels instead of looping its own content.
+        let region = sprite.get_region_rect();
+        let partial_region = sprite.is_region_enabled()
+            && (region.size.x < wrap_size.x || region.size.y < wrap_size.y);
+        if partial_region || texture.try_cast::<AtlasTexture>().is_ok() {
+            godot_warn!(
+                "{}: auto-scroll needs its own tileable texture, not part of an atlas, skipping",
+                sprite.get_name()
+            );
+            commands.entity(entity).insert(AutoScrollChecked);
+            continue;
+        }
+
+        // Scrolling is done by moving the sprite's region over a repeating texture,
+        // so the layer never runs out of pixels to show.
+        if !sprite.is_region_enabled() {
+            sprite.set_region_enabled(true);
+            sprite.set_region_rect(Rect2::new(Vector2::ZERO, wrap_size));
+        }
+        sprite.set_texture_repeat(TextureRepeat::ENABLED);
+
+        commands.entity(entity).insert((
+            AutoScroll {
+                velocity,
+                offset: Vector2::ZERO,
+                region_origin: sprite.get_region_rect().position,
+                wrap_size,
+            },
+            AutoScrollChecked,
+        ));
+    }
+}
+
+// This system advances every auto-scrolling layer by its velocity each frame.
+#[main_thread_system]
+fn auto_scroll_system(
+    mut layers: Query<(&mut GodotNodeHandle, &mut AutoScroll)>,
+    process_delta: Res<Time>,
+) {
+    let delta = process_delta.as_ref().delta_secs();
+    for (mut node_handle, mut auto_scroll) in layers.iter_mut() {
+        auto_scroll.offset = advance_offset(
+            auto_scroll.offset,
+            auto_scroll.velocity,
+            delta,
+            auto_scroll.wrap_size,
+        );
+
+        let mut sprite = node_handle.get::<Sprite2D>();
+        let region = sprite.get_region_rect();
+        sprite.set_region_rect(Rect2::new(
+            auto_scroll.region_origin + auto_scroll.offset,
+            region.size,
+        ));
+    }
+}
+
+// Moves the region `offset` so the content scrolls by `velocity * delta`, wrapping each axis
+// back into `0..wrap_size`.
+//
+// Moving the region right shows content further right, which slides the visible content left,
+// so the region moves against `velocity`.
+fn advance_offset(offset: Vector2, velocity: Vector2, delta: f32, wrap_size: Vector2) -> Vector2 {
+    let moved = offset - velocity * delta;
+    Vector2::new(wrap(moved.x, wrap_size.x), wrap(moved.y, wrap_size.y))
+}
+
+fn wrap(value: f32, size: f32) -> f32 {
+    if size > 0.0 {
+        value.rem_euclid(size)
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The region moves against the velocity (see `advance_offset`), so a negative velocity
+    // runs past the texture width and a positive one runs below zero.
+
+    #[test]
+    fn one_step_moves_region_against_velocity() {
+        let offset = advance_offset(
+            Vector2::new(50.0, 50.0),
+            Vector2::new(10.0, -20.0),
+            0.5,
+            Vector2::new(100.0, 100.0),
+        );
+        assert_eq!(offset, Vector2::new(45.0, 60.0));
+    }
+
+    #[test]
+    fn wraps_past_texture_width_back_into_range() {
+        let offset = advance_offset(
+            Vector2::new(98.0, 0.0),
+            Vector2::new(-10.0, 0.0),
+            0.5,
+            Vector2::new(100.0, 100.0),
+        );
+        assert_eq!(offset, Vector2::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn wraps_below_zero_to_top_of_range() {
+        let offset = advance_offset(
+            Vector2::new(2.0, 0.0),
+            Vector2::new(10.0, 0.0),
+            0.5,
+            Vector2::new(100.0, 100.0),
+        );
+        assert_eq!(offset, Vector2::new(97.0, 0.0));
+    }
+
+    #[test]
+    fn zero_wrap_size_passes_value_through() {
+        let offset = advance_offset(Vector2::ZERO, Vector2::new(10.0, 20.0), 0.5, Vector2::ZERO);
+        assert_eq!(offset, Vector2::new(-5.0, -10.0));
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::ecs::system::Query;
+use bevy::prelude::{App, Entity, Plugin, Res, ResMut, Resource, State, Time, Update};
+use godot::builtin::Vector2;
+use godot::classes::{CanvasLayer, Input, Label};
+use godot::meta::ToGodot;
+use godot_bevy::prelude::{GodotNodeHandle, SceneTreeRef, main_thread_system};
+
+use crate::GameState;
+
+mod collision_log;
+mod collision_shapes;
+mod free_camera;
+
+pub use collision_log::CollisionDebug;
+use collision_log::CollisionLogPlugin;
+use collision_shapes::CollisionShapesPlugin;
+use free_camera::FreeCameraPlugin;
+
+// Name of the input action that shows and hides the overlay (F3 by default, see project.godot).
+const TOGGLE_DEBUG_ACTION: &str = "toggle_debug";
+
+// Draw the overlay above everything else in the game.
+const DEBUG_OVERLAY_LAYER: i32 = 100;
+
+// Development tools: an overlay showing FPS, entity count and the current `GameState`,
+// a free camera for inspecting levels (see `free_camera.rs`), a collision shape
+// visualizer (see `collision_shapes.rs`) and an opt-in collision log (see `collision_log.rs`).
+//
+// Only compiled when building with `--features debug`, so it never ships in release builds.
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlay>()
+            .add_systems(Update, (toggle_debug_overlay, update_debug_overlay))
+            .add_plugins((FreeCameraPlugin, CollisionShapesPlugin, CollisionLogPlugin));
+    }
+}
+
+// Handles to the overlay's Godot nodes. They are created the first time the overlay is shown.
+#[derive(Resource, Default)]
+struct DebugOverlay {
+    layer: Option<GodotNodeHandle>,
+    label: Option<GodotNodeHandle>,
+    visible: bool,
+}
+
+// This system shows or hides the overlay whenever the `toggle_debug` action is pressed.
+#[main_thread_system]
+fn toggle_debug_overlay(mut overlay: ResMut<DebugOverlay>, mut scene_tree: SceneTreeRef) {
+    if !Input::singleton().is_action_just_pressed(TOGGLE_DEBUG_ACTION) {
+        return;
+    }
+
+    if overlay.layer.is_none() {
+        let mut layer = CanvasLayer::new_alloc();
+        layer.set_name("DebugOverlay");
+        layer.set_layer(DEBUG_OVERLAY_LAYER);
+
+        let mut label = Label::new_alloc();
+        label.set_position(Vector2::new(8.0, 8.0));
+        layer.add_child(&label);
+
+        // The root may be busy processing this frame, so add the overlay once it is idle.
+        if let Some(mut root) = scene_tree.get().get_root() {
+            root.call_deferred("add_child", &[layer.to_variant()]);
+        }
+
+        overlay.label = Some(GodotNodeHandle::new(label));
+        overlay.layer = Some(GodotNodeHandle::new(layer));
+    }
+
+    overlay.visible = !overlay.visible;
+    let visible = overlay.visible;
+    if let Some(layer) = overlay.layer.as_mut() {
+        layer.get::<CanvasLayer>().set_visible(visible);
+    }
+}
+
+// This system refreshes the overlay text every frame while it is visible.
+#[main_thread_system]
+fn update_debug_overlay(
+    mut overlay: ResMut<DebugOverlay>,
+    entities: Query<Entity>,
+    game_state: Res<State<GameState>>,
+    process_delta: Res<Time>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Some(label) = overlay.label.as_mut() else {
+        return;
+    };
+
+    let delta = process_delta.as_ref().delta_secs();
+    let fps = if delta > 0.0 { 1.0 / delta } else { 0.0 };
+    let text = format!(
+        "FPS: {:.0}\nEntities: {}\nState: {:?}",
+        fps,
+        entities.iter().count(),
+        game_state.get()
+    );
+    label.get::<Label>().set_text(text.as_str());
+}
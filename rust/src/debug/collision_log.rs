@@ -0,0 +1,78 @@
+use bevy::prelude::{App, Entity, IntoScheduleConfigs, Name, Plugin, Res, Resource, Update, World};
+use godot::global::godot_print;
+use godot_bevy::prelude::{Collisions, GodotCollisionsPlugin};
+
+// Logs every new collision to the Godot console, to help figure out why a collision-driven
+// interaction "isn't working". Where F5 (`collision_shapes.rs`) shows *where* the shapes are,
+// this log shows *which entities* actually touched.
+//
+// Off by default so the console isn't spammed, and not tied to any key. To turn it on, build with
+// `--features debug` and insert the resource in `build_app`:
+// `app.insert_resource(CollisionDebug(true));`
+pub struct CollisionLogPlugin;
+
+impl Plugin for CollisionLogPlugin {
+    fn build(&self, app: &mut App) {
+        // `Collisions` components are only filled in by the `GodotCollisionsPlugin`;
+        // add it unless another plugin already did.
+        if !app.is_plugin_added::<GodotCollisionsPlugin>() {
+            app.add_plugins(GodotCollisionsPlugin);
+        }
+
+        app.init_resource::<CollisionDebug>().add_systems(
+            Update,
+            log_collisions.run_if(|debug: Res<CollisionDebug>| debug.0),
+        );
+    }
+}
+
+// Whether collisions are logged.
+#[derive(Resource, Debug, Default)]
+pub struct CollisionDebug(pub bool);
+
+// This system prints each entry of `recent_collisions()` along with the marker components
+// of both entities, e.g. `Area2DMarker`, so you can see what actually touched what.
+//
+// It takes the whole `World` because it needs to list the components of arbitrary entities.
+fn log_collisions(world: &mut World) {
+    let mut query = world.query::<(Entity, &Collisions)>();
+    let collisions: Vec<(Entity, Vec<Entity>)> = query
+        .iter(world)
+        .filter(|(_, collisions)| !collisions.recent_collisions().is_empty())
+        .map(|(entity, collisions)| (entity, collisions.recent_collisions().to_vec()))
+        .collect();
+
+    for (entity, others) in collisions {
+        for other in others {
+            godot_print!(
+                "Collision: {} <-> {}",
+                describe_entity(world, entity),
+                describe_entity(world, other)
+            );
+        }
+    }
+}
+
+// Formats an entity as `Name (12v1) [Marker, ...]`.
+fn describe_entity(world: &World, entity: Entity) -> String {
+    let name = world
+        .get::<Name>(entity)
+        .map(|name| format!("{name} "))
+        .unwrap_or_default();
+
+    let markers = match world.inspect_entity(entity) {
+        Ok(components) => components
+            .map(|info| short_type_name(info.name()))
+            .filter(|name| name.ends_with("Marker"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Err(_) => "despawned".to_string(),
+    };
+
+    format!("{name}({entity}) [{markers}]")
+}
+
+// Strips the module path from a type name, e.g. `godot_bevy::...::Area2DMarker` -> `Area2DMarker`.
+fn short_type_name(name: &str) -> &str {
+    name.rsplit("::").next().unwrap_or(name)
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::{App, DetectChanges, Plugin, Res, ResMut, Resource, Update};
+use godot::classes::Input;
+use godot::global::godot_print;
+use godot_bevy::prelude::{SceneTreeRef, main_thread_system};
+
+// Input action that shows and hides collision shapes (F5 by default, see project.godot).
+const TOGGLE_COLLISION_DEBUG_ACTION: &str = "toggle_collision_debug";
+
+// Shows every collision shape at runtime, the same as "Visible Collision Shapes" in the
+// editor's Debug menu, to help tune hitboxes.
+pub struct CollisionShapesPlugin;
+
+impl Plugin for CollisionShapesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugCollisionShapes>().add_systems(
+            Update,
+            (toggle_collision_shapes, apply_collision_shapes).chain(),
+        );
+    }
+}
+
+// Whether collision shapes are drawn. Bevy resources outlive Godot scenes, so the choice
+// survives level changes and reloads.
+#[derive(Resource, Debug, Default)]
+pub struct DebugCollisionShapes(pub bool);
+
+// This system flips the setting whenever `toggle_collision_debug` is pressed.
+#[main_thread_system]
+fn toggle_collision_shapes(mut shapes: ResMut<DebugCollisionShapes>) {
+    if Input::singleton().is_action_just_pressed(TOGGLE_COLLISION_DEBUG_ACTION) {
+        shapes.0 = !shapes.0;
+    }
+}
+
+// This system hands the setting to Godot whenever it changes.
+#[main_thread_system]
+fn apply_collision_shapes(shapes: Res<DebugCollisionShapes>, mut scene_tree: SceneTreeRef) {
+    // Skip the first run, when the resource is only "changed" because it was just added.
+    if !shapes.is_changed() || shapes.is_added() {
+        return;
+    }
+
+    let mut tree = scene_tree.get();
+    tree.set_debug_collisions_hint(shapes.0);
+
+    // Godot only picks up the hint for shapes created after it is set,
+    // so reload the scene to show or hide the existing ones.
+    godot_print!("Collision shapes visible: {}, reloading scene", shapes.0);
+    tree.reload_current_scene();
+}
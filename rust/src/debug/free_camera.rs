@@ -0,0 +1,142 @@
+use bevy::prelude::{App, Plugin, Res, ResMut, Resource, Time, Update};
+use godot::builtin::Vector2;
+use godot::classes::{Camera2D, Input};
+use godot::global::MouseButton;
+use godot_bevy::prelude::{GodotNodeHandle, SceneTreeRef, main_thread_system};
+
+// Input actions, see project.godot: F4 toggles, the mouse wheel zooms and the numpad arrows
+// (4/6/8/2) pan. Panning has its own keys so it never drives the player's movement actions.
+const TOGGLE_FREE_CAM_ACTION: &str = "toggle_free_cam";
+const ZOOM_IN_ACTION: &str = "free_cam_zoom_in";
+const ZOOM_OUT_ACTION: &str = "free_cam_zoom_out";
+const PAN_LEFT_ACTION: &str = "free_cam_pan_left";
+const PAN_RIGHT_ACTION: &str = "free_cam_pan_right";
+const PAN_UP_ACTION: &str = "free_cam_pan_up";
+const PAN_DOWN_ACTION: &str = "free_cam_pan_down";
+
+// Pan speed for the pan keys, in screen pixels per second.
+const PAN_SPEED: f32 = 400.0;
+// How much a single mouse wheel step zooms in or out.
+const ZOOM_STEP: f32 = 1.1;
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 10.0;
+
+// Detaches the current Camera2D from what it follows so a level can be inspected freely.
+//
+// Pan with the numpad arrows or by dragging with the middle mouse button, zoom with the wheel.
+// The camera is made `top_level` so it stops following its parent (the player in every level),
+// and its drag margins are turned off. Turning the free camera off restores all of that, which
+// snaps it straight back to following its parent without touching gameplay.
+pub struct FreeCameraPlugin;
+
+impl Plugin for FreeCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FreeCamera>()
+            .add_systems(Update, (toggle_free_camera, move_free_camera));
+    }
+}
+
+// The camera currently under free control, and its settings from before it was detached.
+#[derive(Resource, Default)]
+struct FreeCamera {
+    camera: Option<GodotNodeHandle>,
+    saved_top_level: bool,
+    saved_position: Vector2,
+    saved_zoom: Vector2,
+    saved_drag: (bool, bool),
+    last_mouse_position: Option<Vector2>,
+}
+
+// This system detaches or restores the active Camera2D when `toggle_free_cam` is pressed.
+#[main_thread_system]
+fn toggle_free_camera(mut free_camera: ResMut<FreeCamera>, mut scene_tree: SceneTreeRef) {
+    if !Input::singleton().is_action_just_pressed(TOGGLE_FREE_CAM_ACTION) {
+        return;
+    }
+
+    if let Some(mut handle) = free_camera.camera.take() {
+        // The camera may have been freed by a scene change while it was detached.
+        if let Some(mut camera) = handle.try_get::<Camera2D>() {
+            camera.set_as_top_level(free_camera.saved_top_level);
+            camera.set_position(free_camera.saved_position);
+            camera.set_zoom(free_camera.saved_zoom);
+            camera.set_drag_horizontal_enabled(free_camera.saved_drag.0);
+            camera.set_drag_vertical_enabled(free_camera.saved_drag.1);
+            camera.reset_smoothing();
+        }
+        free_camera.last_mouse_position = None;
+        return;
+    }
+
+    let Some(mut camera) = scene_tree
+        .get()
+        .get_root()
+        .and_then(|root| root.get_camera_2d())
+    else {
+        return;
+    };
+    free_camera.saved_top_level = camera.is_set_as_top_level();
+    free_camera.saved_position = camera.get_position();
+    free_camera.saved_zoom = camera.get_zoom();
+    free_camera.saved_drag = (
+        camera.is_drag_horizontal_enabled(),
+        camera.is_drag_vertical_enabled(),
+    );
+
+    // A top-level node ignores its parent's transform, so it stays put while the player moves.
+    // Its position is then in global coordinates, so keep it where it is on screen.
+    let global_position = camera.get_global_position();
+    camera.set_as_top_level(true);
+    camera.set_global_position(global_position);
+    camera.set_drag_horizontal_enabled(false);
+    camera.set_drag_vertical_enabled(false);
+    free_camera.camera = Some(GodotNodeHandle::new(camera));
+}
+
+// This system pans and zooms the detached camera from keyboard and mouse input.
+#[main_thread_system]
+fn move_free_camera(mut free_camera: ResMut<FreeCamera>, process_delta: Res<Time>) {
+    let Some(handle) = free_camera.camera.as_mut() else {
+        return;
+    };
+    let Some(mut camera) = handle.try_get::<Camera2D>() else {
+        // The camera was freed, e.g. by a scene change, so there is nothing left to control.
+        *free_camera = FreeCamera::default();
+        return;
+    };
+
+    let input = Input::singleton();
+    let mut zoom = camera.get_zoom();
+    if input.is_action_just_pressed(ZOOM_IN_ACTION) {
+        zoom *= ZOOM_STEP;
+    }
+    if input.is_action_just_pressed(ZOOM_OUT_ACTION) {
+        zoom /= ZOOM_STEP;
+    }
+    zoom = zoom.clamp(Vector2::splat(MIN_ZOOM), Vector2::splat(MAX_ZOOM));
+    camera.set_zoom(zoom);
+
+    // Screen-space movement is divided by the zoom so panning feels the same at every zoom level.
+    let mut pan = Vector2::ZERO;
+    let key_direction = input.get_vector(
+        PAN_LEFT_ACTION,
+        PAN_RIGHT_ACTION,
+        PAN_UP_ACTION,
+        PAN_DOWN_ACTION,
+    );
+    pan += key_direction * PAN_SPEED * process_delta.as_ref().delta_secs();
+
+    let mouse_position = camera
+        .get_viewport()
+        .map(|viewport| viewport.get_mouse_position());
+    if input.is_mouse_button_pressed(MouseButton::MIDDLE) {
+        if let (Some(last), Some(current)) = (free_camera.last_mouse_position, mouse_position) {
+            pan -= current - last;
+        }
+        free_camera.last_mouse_position = mouse_position;
+    } else {
+        free_camera.last_mouse_position = None;
+    }
+
+    camera.set_position(camera.get_position() + pan / zoom);
+}
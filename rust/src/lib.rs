@@ -1,18 +1,28 @@
 #![allow(unexpected_cfgs)] // silence potential `tracy_trace` feature config warning brought in by `bevy_app` macro
-use bevy::ecs::system::Query;
-use bevy::prelude::{
-    App, Commands, Component, Entity, IntoScheduleConfigs, Res, Time, Update, Without,
-};
-use bevy::transform::components::Transform;
-use godot::builtin::Vector2;
-use godot::classes::Sprite2D;
+use bevy::prelude::{App, AppExtStates, Plugin};
+use bevy::state::app::StatesPlugin;
 use godot::global::godot_print;
 use godot_bevy::prelude::godot_prelude::ExtensionLibrary;
 use godot_bevy::prelude::godot_prelude::gdextension;
-use godot_bevy::prelude::{
-    GodotNodeHandle, GodotTransformSyncPlugin, Sprite2DMarker, bevy_app, main_thread_system,
-};
-use std::f32::consts::PI;
+use godot_bevy::prelude::{GodotTransformSyncPlugin, bevy_app};
+
+mod auto_scroll;
+#[cfg(feature = "debug")]
+mod debug;
+mod one_way_platform;
+#[cfg(feature = "orbit_demo")]
+mod orbit_demo;
+mod states;
+
+use auto_scroll::AutoScrollPlugin;
+#[cfg(feature = "debug")]
+pub use debug::CollisionDebug;
+#[cfg(feature = "debug")]
+use debug::DebugPlugin;
+use one_way_platform::OneWayPlatformPlugin;
+#[cfg(feature = "orbit_demo")]
+use orbit_demo::OrbitDemoPlugin;
+pub use states::GameState;
 
 // The build_app function runs at your game's startup.
 //
@@ -30,91 +40,44 @@ fn build_app(app: &mut App) {
     // (https://docs.rs/godot-core/0.3.1/godot_core/macro.godot_print.html)
     godot_print!("Hello from Godot-Bevy!");
 
-    // Add the transform syncing plugin since we're using Transform components
-    app.add_plugins(GodotTransformSyncPlugin::default());
-
-    // A system is a normal Rust function.
-    //
-    // This line runs the `orbit_setup` and then the
-    // `orbit_system` functions every Godot render frame.
-    //
-    // Read more about Bevy's Entities, Components, and Systems here:
-    // (https://bevy.org/learn/quick-start/getting-started/ecs/).
-    //
-    // Godot-Bevy synchronizes the Bevy 'Update' schedule parameter with the
-    // Godot `_process` update cycle. There is also a `PhysicsUpdate` schedule
-    // parameter that is synchronized with the Godot `_physics_process` update cycle.
-    //
-    // Read more about other schedules provided by Godot-Bevy here:
-    // (https://bytemeadow.github.io/godot-bevy/scene-tree/timing.html).
-    app.add_systems(Update, (orbit_setup, orbit_system).chain());
+    // Everything the game needs is bundled in `GamePlugin`, see below.
+    app.add_plugins(GamePlugin);
 }
 
-// Components are data that can be attached to entities.
-// This one will store the starting position of a Node2D.
-#[derive(Debug, Component)]
-struct InitialPosition {
-    pos: Vector2,
-}
+// Bundles all of the game's plugins so `build_app` only has to add one.
+//
+// Add new plugins here rather than in `build_app` as the game grows.
+pub struct GamePlugin;
 
-// This component tracks the angle at which the Node2D is orbiting its starting position.
-#[derive(Debug, Component)]
-struct Orbiter {
-    angle: f32,
-}
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        // States are driven by the `StatesPlugin`; add it unless another plugin already did.
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        // Start on the `#[default]` state, `GameState::MainMenu`.
+        app.init_state::<GameState>();
 
-// This component is used as a marker to keep track of which nodes have been initialized.
-#[derive(Debug, Component)]
-struct NodeInitialized;
+        // Add the transform syncing plugin since we're using Transform components
+        app.add_plugins(GodotTransformSyncPlugin::default());
 
-// This system initializes Sprite2Ds with the required components to allow the orbit_system to manipulate them.
-#[main_thread_system]
-fn orbit_setup(
-    // Bevy Commands allow us to modify the state of the world, such as adding components to entities.
-    mut commands: Commands,
+        // Scroll any Sprite2D tagged with `auto_scroll_velocity` metadata, see `auto_scroll.rs`.
+        app.add_plugins(AutoScrollPlugin);
 
-    // Gather all Godot nodes without the `NodeInitialized` component.
-    // Also, include the Bevy entity identifier so we can add components to it.
-    mut uninitialized: Query<
-        (Entity, &mut GodotNodeHandle, &Sprite2DMarker),
-        Without<NodeInitialized>,
-    >,
-) {
-    for (entity, mut node_handle, _) in uninitialized.iter_mut() {
-        let sprite_node = node_handle.get::<Sprite2D>();
-        // The GodotNodeHandle allows us to call Godot methods such as `get_name()`.
-        godot_print!(
-            "Initializing node: {:?}",
-            sprite_node.get_name().to_string()
-        );
-        // Attach new components to the entity.
-        commands
-            .entity(entity)
-            .insert(InitialPosition {
-                pos: sprite_node.get_transform().origin,
-            })
-            .insert(Orbiter { angle: 0.0 })
-            .insert(NodeInitialized);
-    }
-}
+        // Make any CollisionShape2D tagged with `one_way_platform` metadata pass-through from
+        // below, see `one_way_platform.rs`.
+        app.add_plugins(OneWayPlatformPlugin);
 
-// This system orbits entities created above
-fn orbit_system(
-    // The `transform` parameter is a Bevy `Query` that matches all `Transform` components.
-    // `Transform` is a Godot-Bevy-provided component that matches all Node2Ds in the scene.
-    // (https://docs.rs/godot-bevy/latest/godot_bevy/plugins/core/transforms/struct.Transform.html)
-    mut transform: Query<(&mut Transform, &InitialPosition, &mut Orbiter)>,
+        // The orbit demo moves every Sprite2D in the scene, which would fight with a real game,
+        // so it is only added when building with `--features orbit_demo`.
+        #[cfg(feature = "orbit_demo")]
+        app.add_plugins(OrbitDemoPlugin);
 
-    // This is equivalent to Godot's `_process` `delta: float` parameter.
-    process_delta: Res<Time>,
-) {
-    // For single matches, you can use `single_mut()` instead:
-    // `if let Ok(mut transform) = transform.single_mut() {`
-    for (mut transform, initial_position, mut orbiter) in transform.iter_mut() {
-        let position2d = initial_position.pos + Vector2::from_angle(orbiter.angle) * 100.0;
-        transform.translation.x = position2d.x;
-        transform.translation.y = position2d.y;
-        orbiter.angle += process_delta.as_ref().delta_secs();
-        orbiter.angle %= 2.0 * PI;
+        // Development tools: F3 (`toggle_debug`) shows FPS, entity count and the current state,
+        // F4 (`toggle_free_cam`) detaches the camera and F5 (`toggle_collision_debug`) shows
+        // collision shapes. Setting `CollisionDebug(true)` also logs every collision.
+        // Only built with `--features debug`.
+        #[cfg(feature = "debug")]
+        app.add_plugins(DebugPlugin);
     }
 }
@@ -0,0 +1,64 @@
+use bevy::ecs::system::Query;
+use bevy::prelude::{App, Commands, Component, Entity, Plugin, Update, Without};
+use godot::classes::CollisionShape2D;
+use godot::global::godot_warn;
+use godot_bevy::prelude::{GodotNodeHandle, main_thread_system};
+
+// Name of the metadata entry designers set on a CollisionShape2D to make it a one-way platform.
+//
+// In the Godot editor, select the platform's CollisionShape2D, click "Add Metadata" at the bottom
+// of the inspector, name it `one_way_platform` and give it the `bool` value `true`. The player can
+// then jump up through the platform and land on it from above.
+const ONE_WAY_PLATFORM_META: &str = "one_way_platform";
+
+// Turns collision shapes tagged with `one_way_platform` metadata into pass-through platforms.
+pub struct OneWayPlatformPlugin;
+
+impl Plugin for OneWayPlatformPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, one_way_platform_setup);
+    }
+}
+
+// This component marks collision shapes that only collide from above.
+#[derive(Debug, Component)]
+pub struct OneWayPlatform;
+
+// Marker for nodes that have already been checked for one-way platform metadata.
+#[derive(Debug, Component)]
+struct OneWayPlatformChecked;
+
+// This system looks for CollisionShape2Ds tagged with the one-way metadata and enables
+// Godot's one-way collision on them, in the same way `orbit_setup` initializes Sprite2Ds.
+#[main_thread_system]
+fn one_way_platform_setup(
+    mut commands: Commands,
+    mut unchecked: Query<(Entity, &mut GodotNodeHandle), Without<OneWayPlatformChecked>>,
+) {
+    for (entity, mut node_handle) in unchecked.iter_mut() {
+        commands.entity(entity).insert(OneWayPlatformChecked);
+
+        let Some(mut shape) = node_handle.try_get::<CollisionShape2D>() else {
+            continue;
+        };
+        if !shape.has_meta(ONE_WAY_PLATFORM_META) {
+            continue;
+        }
+        let Ok(one_way) = shape.get_meta(ONE_WAY_PLATFORM_META).try_to::<bool>() else {
+            godot_warn!(
+                "{}: `{}` must be a bool, skipping one-way platform",
+                shape.get_name(),
+                ONE_WAY_PLATFORM_META
+            );
+            continue;
+        };
+        if !one_way {
+            continue;
+        }
+
+        // One-way shapes only block bodies moving against the shape's local "down",
+        // so rotate the shape to change which side is solid.
+        shape.set_one_way_collision(true);
+        commands.entity(entity).insert(OneWayPlatform);
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::States;
+
+// The screens the game can be on. Only one is active at a time.
+//
+// Systems can be limited to a state with `.run_if(in_state(GameState::InGame))`,
+// and state changes are requested through `ResMut<NextState<GameState>>`.
+// Read more about Bevy states here:
+// (https://docs.rs/bevy/0.16.1/bevy/state/index.html)
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameState {
+    #[default]
+    MainMenu,
+    InGame,
+    Paused,
+    GameOver,
+    LevelSelect,
+    Settings,
+}